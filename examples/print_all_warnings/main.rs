@@ -4,7 +4,7 @@ fn main() {
     let warning_list = WarningList::get_new().unwrap();
 
     warning_list
+        .current_only()
         .into_iter()
-        .filter(|f| f.is_current())
         .for_each(|w| println!("{w:?}"));
 }
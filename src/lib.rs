@@ -1,31 +1,140 @@
 //! This client provides a handy wrapper around the dwd weather alerts api.
-//! Get a list of warnings with `WarningList::get_new()`
+//! Get a list of warnings with `WarningList::get_new()` (requires the `blocking` feature)
+//! or `WarningList::get_new_async()` for use from within a tokio runtime.
 
 #[doc(hidden)]
 pub use chrono::{DateTime, NaiveDateTime, Utc};
+#[cfg(feature = "blocking")]
 use reqwest::blocking;
 use serde::Deserialize;
 
 const API_URL: &str = "https://www.dwd.de/DWD/warnungen/warnapp/json/warnings.json";
 
-#[derive(Debug)]
+#[derive(Debug, thiserror::Error)]
 pub enum Error {
-    DeserializationError(serde_json::Error),
+    #[error("failed to deserialize warning data: {0}")]
+    DeserializationError(#[from] serde_json::Error),
+    #[error("the response did not match the expected jsonp wrapper")]
     ResponseProcessingError,
-    RequestResponseError(reqwest::Error),
-    DateParsingError,
+    #[error("request failed: {0}")]
+    RequestResponseError(#[from] reqwest::Error),
+    #[error("could not parse timestamp `{timestamp}` into a valid date")]
+    DateParsingError { timestamp: i64 },
 }
 
-impl From<reqwest::Error> for Error {
-    fn from(value: reqwest::Error) -> Self {
-        return Error::RequestResponseError(value);
+/// Deserializes a JSON string field leniently, replacing unpaired UTF-16 surrogates with the
+/// Unicode replacement character (U+FFFD) instead of failing the whole payload. The dwd feed's
+/// free-form German text fields occasionally contain such sequences.
+///
+/// Escape sequences that aren't valid JSON to begin with (e.g. `\z`) can't be handled here —
+/// they prevent the surrounding document from tokenizing at all, so they must be fixed up in the
+/// raw response text before it reaches serde_json; see [`sanitize_invalid_json_escapes`].
+///
+/// Requires serde_json's `raw_value` Cargo feature, which must be enabled in `Cargo.toml` for
+/// `serde_json::value::RawValue` to be available.
+fn deserialize_lossy_string<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let raw = <&serde_json::value::RawValue>::deserialize(deserializer)?;
+    let text = raw.get();
+    let inner = text
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .unwrap_or(text);
+
+    Ok(unescape_json_string_lossy(inner))
+}
+
+/// Rewrites backslash escapes in raw (pre-parse) JSON text that aren't valid JSON escape
+/// sequences (i.e. not one of `" \ / b f n r t u`) into the escape for the Unicode replacement
+/// character, so a single malformed upstream payload still tokenizes instead of being rejected
+/// by `serde_json` outright. `\u` escapes that form an unpaired surrogate are left untouched
+/// here — they're syntactically valid JSON and are handled per-field by
+/// [`deserialize_lossy_string`] instead.
+fn sanitize_invalid_json_escapes(text: &str) -> std::borrow::Cow<'_, str> {
+    const VALID_ESCAPES: &[u8] = b"\"\\/bfnrtu";
+
+    let bytes = text.as_bytes();
+    let has_invalid_escape = bytes
+        .windows(2)
+        .any(|w| w[0] == b'\\' && !VALID_ESCAPES.contains(&w[1]));
+    if !has_invalid_escape {
+        return std::borrow::Cow::Borrowed(text);
+    }
+
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut in_string = false;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let b = bytes[i];
+
+        if in_string && b == b'\\' {
+            match bytes.get(i + 1) {
+                Some(&c) if VALID_ESCAPES.contains(&c) => {
+                    out.push(b'\\');
+                    out.push(c);
+                    i += 2;
+                }
+                Some(_) => {
+                    out.extend_from_slice(b"\\ufffd");
+                    // Drop the whole escaped character, which may span several UTF-8 bytes.
+                    let escaped_len = text[i + 1..].chars().next().map_or(1, char::len_utf8);
+                    i += 1 + escaped_len;
+                }
+                None => {
+                    out.push(b'\\');
+                    i += 1;
+                }
+            }
+            continue;
+        }
+
+        if b == b'"' {
+            in_string = !in_string;
+        }
+
+        out.push(b);
+        i += 1;
     }
+
+    // We only ever inspect single ASCII bytes (`\` and `"`) and replace an invalid escape
+    // together with its whole escaped character; every other byte, including multi-byte UTF-8
+    // sequences, is copied through verbatim, so the result stays valid UTF-8.
+    std::borrow::Cow::Owned(String::from_utf8(out).expect("sanitizing only touches ASCII bytes"))
 }
 
-impl From<serde_json::Error> for Error {
-    fn from(value: serde_json::Error) -> Self {
-        return Error::DeserializationError(value);
+/// Decodes the escape sequences of a JSON string literal's contents (without the surrounding
+/// quotes), collecting UTF-16 code units and converting them with `String::from_utf16_lossy` so
+/// unpaired surrogates become U+FFFD rather than an error.
+fn unescape_json_string_lossy(input: &str) -> String {
+    let mut chars = input.chars().peekable();
+    let mut units: Vec<u16> = Vec::with_capacity(input.len());
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            let mut buf = [0u16; 2];
+            units.extend_from_slice(c.encode_utf16(&mut buf));
+            continue;
+        }
+
+        match chars.next() {
+            Some('u') => {
+                let code: String = (0..4).filter_map(|_| chars.next()).collect();
+                units.push(u16::from_str_radix(&code, 16).unwrap_or(0xFFFD));
+            }
+            Some('n') => units.push(b'\n' as u16),
+            Some('t') => units.push(b'\t' as u16),
+            Some('r') => units.push(b'\r' as u16),
+            Some('b') => units.push(0x08),
+            Some('f') => units.push(0x0C),
+            Some(other @ ('"' | '\\' | '/')) => units.push(other as u16),
+            Some(_) | None => units.push(0xFFFD),
+        }
     }
+
+    String::from_utf16_lossy(&units)
 }
 
 #[derive(Deserialize, Debug)]
@@ -39,8 +148,11 @@ struct WarningRaw {
     end: Option<i64>,
     region_name: String,
     event: String,
+    #[serde(deserialize_with = "deserialize_lossy_string")]
     headline: String,
+    #[serde(deserialize_with = "deserialize_lossy_string")]
     instruction: String,
+    #[serde(deserialize_with = "deserialize_lossy_string")]
     description: String,
     state_short: String,
     altitude_start: Option<i64>,
@@ -56,10 +168,12 @@ struct WarningResponse {
     vorab_information: std::collections::HashMap<(), ()>,
     copyright: String,
 }
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 #[allow(unused)]
 /// Represents an individual warning.
 pub struct Warning {
+    /// The id of the warn cell this warning was issued for, taken from the warnings map's key.
+    pub warn_cell_id: String,
     pub state: String,
     pub category: u8,
     pub level: u8,
@@ -88,19 +202,24 @@ impl Warning {
     }
 }
 
-impl From<WarningRaw> for Warning {
-    fn from(value: WarningRaw) -> Self {
-        let start = chrono::NaiveDateTime::from_timestamp_millis(value.start).unwrap();
+impl TryFrom<(String, WarningRaw)> for Warning {
+    type Error = Error;
+    fn try_from((warn_cell_id, value): (String, WarningRaw)) -> Result<Self, Error> {
+        let start = chrono::NaiveDateTime::from_timestamp_millis(value.start)
+            .ok_or(Error::DateParsingError {
+                timestamp: value.start,
+            })?;
         let start = chrono::DateTime::<Utc>::from_utc(start, Utc);
-        let end = value.end;
-        let end = if let Some(c) = end {
-            let t = chrono::NaiveDateTime::from_timestamp_millis(c).unwrap();
+        let end = if let Some(c) = value.end {
+            let t = chrono::NaiveDateTime::from_timestamp_millis(c)
+                .ok_or(Error::DateParsingError { timestamp: c })?;
             Some(chrono::DateTime::<Utc>::from_utc(t, Utc))
         } else {
             None
         };
 
-        Warning {
+        Ok(Warning {
+            warn_cell_id,
             state: value.state,
             category: value.category,
             level: value.level,
@@ -114,7 +233,7 @@ impl From<WarningRaw> for Warning {
             state_short: value.state_short,
             altitude_start: value.altitude_start,
             altitude_end: value.altitude_end,
-        }
+        })
     }
 }
 
@@ -127,9 +246,37 @@ pub struct WarningList {
     pub copyright: String,
 }
 
+/// Strips the `warnWetter.loadWarnings(...)` JSONP wrapper the dwd API wraps its payload in.
+fn strip_jsonp(raw_response: &str) -> Result<&str, Error> {
+    let data = match raw_response.strip_prefix("warnWetter.loadWarnings(") {
+        Some(s) => s,
+        None => return Err(Error::ResponseProcessingError),
+    };
+    let data = match data.strip_suffix(");") {
+        Some(s) => s,
+        None => return Err(Error::ResponseProcessingError),
+    };
+
+    Ok(data)
+}
+
+/// Parses a raw JSONP response body into a sorted `WarningList`.
+fn parse_response(raw_response: &str) -> Result<WarningList, Error> {
+    let data = strip_jsonp(raw_response)?;
+    let data = sanitize_invalid_json_escapes(data);
+    let warnings = serde_json::from_str::<WarningResponse>(&data)?;
+    let mut warninglist = WarningList::try_from(warnings)?;
+    warninglist.warnings.sort_by_key(|f| f.start);
+
+    Ok(warninglist)
+}
+
 impl WarningList {
     /// Queries a new warning from the dwd.
     ///
+    /// This uses a blocking request and is only available with the `blocking` feature.
+    /// See [`WarningList::get_new_async`] for an async, non-blocking alternative.
+    ///
     /// # Errors
     /// Returns `Error::RequestResponseError` if the request fails, which then contains the underlying reqwest error.
     ///
@@ -138,26 +285,72 @@ impl WarningList {
     /// Returns `Error::DeserializationError` if the deserialization failed, containing the underlying serde error.
     ///
     /// Returns `Error::DateParsingError` if the date could not be parsed by chrono.
+    #[cfg(feature = "blocking")]
+    pub fn get_new() -> Result<WarningList, Error> {
+        let raw_response = blocking::get(API_URL)?.text()?;
+        parse_response(&raw_response)
+    }
+
+    /// Queries a new warning from the dwd using a non-blocking `reqwest::Client`.
     ///
-    /// # Panics
+    /// Callers should reuse the same `Client` across calls so connections can be pooled.
     ///
-    /// Panics if the start or end field contain out of bounds integers that can not be translated into a valid time.
+    /// # Errors
+    /// Returns `Error::RequestResponseError` if the request fails, which then contains the underlying reqwest error.
     ///
-    pub fn get_new() -> Result<WarningList, Error> {
-        let raw_response = blocking::get(API_URL)?.text()?;
-        let data = match raw_response.strip_prefix("warnWetter.loadWarnings(") {
-            Some(s) => s,
-            None => return Err(Error::ResponseProcessingError),
-        };
-        let data = match data.strip_suffix(");") {
-            Some(s) => s,
-            None => return Err(Error::ResponseProcessingError),
-        };
-        let warnings = serde_json::from_str::<WarningResponse>(&data)?;
-        let mut warninglist = WarningList::try_from(warnings)?;
-        warninglist.warnings.sort_by_key(|f| f.start);
+    /// Returns `Error::ResponseProcessingError` if the returned data dose not match the usual pre- and suffixes.
+    ///
+    /// Returns `Error::DeserializationError` if the deserialization failed, containing the underlying serde error.
+    ///
+    /// Returns `Error::DateParsingError` if the date could not be parsed by chrono.
+    pub async fn get_new_async(client: &reqwest::Client) -> Result<WarningList, Error> {
+        let raw_response = client.get(API_URL).send().await?.text().await?;
+        parse_response(&raw_response)
+    }
+
+    /// Returns a new `WarningList` containing only the warnings for which `predicate` returns
+    /// `true`. The underlying `Warning`s are cloned into the result.
+    fn filter(&self, predicate: impl Fn(&Warning) -> bool) -> WarningList {
+        WarningList {
+            time: self.time,
+            warnings: self
+                .warnings
+                .iter()
+                .filter(|w| predicate(w))
+                .cloned()
+                .collect(),
+            copyright: self.copyright.clone(),
+        }
+    }
+
+    /// Returns a new `WarningList` containing only warnings for the given federal state, e.g.
+    /// `"Nordrhein-Westfalen"` (matches `Warning::state`).
+    pub fn filter_by_state(&self, state: &str) -> WarningList {
+        self.filter(|w| w.state == state)
+    }
+
+    /// Returns a new `WarningList` containing only warnings for the given warn region, matched
+    /// on `Warning::warn_cell_id` rather than `region_name`, since warn cell ids are stable and
+    /// region names are not unique.
+    pub fn filter_by_region(&self, warn_cell_id: &str) -> WarningList {
+        self.filter(|w| w.warn_cell_id == warn_cell_id)
+    }
 
-        return Ok(warninglist);
+    /// Returns a new `WarningList` containing only warnings at or above the given severity
+    /// level.
+    pub fn with_min_level(&self, level: u8) -> WarningList {
+        self.filter(|w| w.level >= level)
+    }
+
+    /// Returns a new `WarningList` containing only warnings of the given event category.
+    pub fn with_category(&self, category: u8) -> WarningList {
+        self.filter(|w| w.category == category)
+    }
+
+    /// Returns a new `WarningList` containing only warnings for which `Warning::is_current()`
+    /// is `true`.
+    pub fn current_only(&self) -> WarningList {
+        self.filter(Warning::is_current)
     }
 }
 
@@ -166,25 +359,28 @@ impl TryFrom<WarningResponse> for WarningList {
     fn try_from(value: WarningResponse) -> Result<Self, Error> {
         let time = match chrono::NaiveDateTime::from_timestamp_millis(value.time) {
             Some(c) => c,
-            None => return Err(Error::DateParsingError),
+            None => {
+                return Err(Error::DateParsingError {
+                    timestamp: value.time,
+                })
+            }
         };
 
         let time = chrono::DateTime::from_utc(time, chrono::Utc);
 
         let mut raw_warnings = Vec::new();
 
-        for (_, inst) in value.warnings {
+        for (warn_cell_id, inst) in value.warnings {
             for warning in inst {
-                raw_warnings.push(warning);
+                raw_warnings.push((warn_cell_id.clone(), warning));
             }
         }
 
         let mut warnings = Vec::new();
 
-        raw_warnings
-            .into_iter()
-            .map(|w| warnings.push(Warning::from(w)))
-            .for_each(drop);
+        for w in raw_warnings {
+            warnings.push(Warning::try_from(w)?);
+        }
 
         return Ok(WarningList {
             time,
@@ -203,21 +399,176 @@ impl IntoIterator for WarningList {
     }
 }
 
+/// A stable identity for a warning, used to match warnings across successive polls.
+type WarningKey = (String, String, i64);
+
+fn warning_key(warning: &Warning) -> WarningKey {
+    (
+        warning.region_name.clone(),
+        warning.event.clone(),
+        warning.start.timestamp_millis(),
+    )
+}
+
+/// A single change observed between two successive polls of the warnings feed.
+#[derive(Debug, Clone)]
+pub enum WarningChange {
+    /// A warning that was not present in the previous poll.
+    Added(Warning),
+    /// A warning that was present in the previous poll but is gone from the latest one.
+    Removed(Warning),
+    /// A warning that is still present, but whose `is_current()` flipped from true to false.
+    Expired(Warning),
+}
+
+/// The outcome of a single [`WarningWatcher::poll`].
+#[derive(Debug, Clone)]
+pub enum PollResult {
+    /// The server reported `304 Not Modified`; there is nothing new to process.
+    Unchanged,
+    /// The feed changed since the last poll, carrying the diff against the previous list.
+    Changed(Vec<WarningChange>),
+}
+
+fn diff_warning_lists(previous: &WarningList, current: &WarningList) -> Vec<WarningChange> {
+    let mut previous_by_key: std::collections::HashMap<WarningKey, &Warning> = previous
+        .warnings
+        .iter()
+        .map(|w| (warning_key(w), w))
+        .collect();
+
+    let mut changes = Vec::new();
+
+    for warning in &current.warnings {
+        match previous_by_key.remove(&warning_key(warning)) {
+            Some(old) if old.is_current() && !warning.is_current() => {
+                changes.push(WarningChange::Expired(warning.clone()));
+            }
+            Some(_) => {}
+            None => changes.push(WarningChange::Added(warning.clone())),
+        }
+    }
+
+    for removed in previous_by_key.into_values() {
+        changes.push(WarningChange::Removed(removed.clone()));
+    }
+
+    changes
+}
+
+/// Watches the dwd warnings feed, using HTTP conditional requests so unchanged polls are cheap
+/// and diffing successive warning lists so callers only react to what actually changed.
+pub struct WarningWatcher {
+    client: reqwest::Client,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    previous: Option<WarningList>,
+}
+
+impl WarningWatcher {
+    /// Creates a watcher with no prior state. The first `poll()` always reports every warning
+    /// in the feed as `WarningChange::Added`.
+    pub fn new(client: reqwest::Client) -> Self {
+        WarningWatcher {
+            client,
+            etag: None,
+            last_modified: None,
+            previous: None,
+        }
+    }
+
+    /// Performs a single poll, sending `If-None-Match`/`If-Modified-Since` headers from the
+    /// previous response when available.
+    ///
+    /// # Errors
+    /// Returns the same errors as [`WarningList::get_new_async`].
+    pub async fn poll(&mut self) -> Result<PollResult, Error> {
+        let mut request = self.client.get(API_URL);
+        if let Some(etag) = &self.etag {
+            request = request.header(reqwest::header::IF_NONE_MATCH, etag);
+        }
+        if let Some(last_modified) = &self.last_modified {
+            request = request.header(reqwest::header::IF_MODIFIED_SINCE, last_modified);
+        }
+
+        let response = request.send().await?;
+
+        if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+            return Ok(PollResult::Unchanged);
+        }
+
+        if let Some(etag) = response.headers().get(reqwest::header::ETAG) {
+            self.etag = etag.to_str().ok().map(str::to_owned);
+        }
+        if let Some(last_modified) = response.headers().get(reqwest::header::LAST_MODIFIED) {
+            self.last_modified = last_modified.to_str().ok().map(str::to_owned);
+        }
+
+        let raw_response = response.text().await?;
+        let warning_list = parse_response(&raw_response)?;
+
+        let changes = match &self.previous {
+            Some(previous) => diff_warning_lists(previous, &warning_list),
+            None => warning_list
+                .warnings
+                .iter()
+                .cloned()
+                .map(WarningChange::Added)
+                .collect(),
+        };
+
+        self.previous = Some(warning_list);
+
+        Ok(PollResult::Changed(changes))
+    }
+
+    /// Turns this watcher into a stream that polls on a fixed interval and yields once per poll
+    /// that produced a change (ticks that came back `304 Not Modified`, or whose diff is empty,
+    /// are silently skipped).
+    ///
+    /// Each item is the `Result` of the underlying [`WarningWatcher::poll`] call, so callers can
+    /// decide how to handle an individual polling failure without killing the stream.
+    pub fn watch(
+        self,
+        interval: std::time::Duration,
+    ) -> impl futures::Stream<Item = Result<Vec<WarningChange>, Error>> {
+        futures::stream::unfold(
+            (self, tokio::time::interval(interval)),
+            |(mut watcher, mut ticker)| async move {
+                loop {
+                    ticker.tick().await;
+                    match watcher.poll().await {
+                        Ok(PollResult::Unchanged) => continue,
+                        Ok(PollResult::Changed(changes)) if changes.is_empty() => continue,
+                        Ok(PollResult::Changed(changes)) => {
+                            return Some((Ok(changes), (watcher, ticker)))
+                        }
+                        Err(e) => return Some((Err(e), (watcher, ticker))),
+                    }
+                }
+            },
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[cfg(feature = "blocking")]
     #[test]
     fn test_get() {
         assert!(WarningList::get_new().is_ok());
     }
 
+    #[cfg(feature = "blocking")]
     #[test]
     fn returns_at_least_1_warning() {
         let warnings = WarningList::get_new().unwrap();
         assert!(warnings.warnings.len() >= 1);
     }
 
+    #[cfg(feature = "blocking")]
     #[test]
     fn warninglist_works_as_iterator() {
         let warning_list = WarningList::get_new().unwrap();
@@ -226,8 +577,143 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_get_async() {
+        let client = reqwest::Client::new();
+        assert!(WarningList::get_new_async(&client).await.is_ok());
+    }
+
+    fn test_warning(region_name: &str, event: &str, start: i64, end: Option<i64>) -> Warning {
+        Warning {
+            warn_cell_id: String::new(),
+            state: String::new(),
+            category: 0,
+            level: 0,
+            start: chrono::DateTime::<Utc>::from_utc(
+                chrono::NaiveDateTime::from_timestamp_millis(start).unwrap(),
+                Utc,
+            ),
+            end: end.map(|e| {
+                chrono::DateTime::<Utc>::from_utc(
+                    chrono::NaiveDateTime::from_timestamp_millis(e).unwrap(),
+                    Utc,
+                )
+            }),
+            region_name: region_name.to_owned(),
+            event: event.to_owned(),
+            headline: String::new(),
+            instruction: String::new(),
+            description: String::new(),
+            state_short: String::new(),
+            altitude_start: None,
+            altitude_end: None,
+        }
+    }
+
+    fn test_warning_list(warnings: Vec<Warning>) -> WarningList {
+        WarningList {
+            time: Utc::now(),
+            warnings,
+            copyright: String::new(),
+        }
+    }
+
+    #[test]
+    fn diff_reports_added_and_removed() {
+        let previous = test_warning_list(vec![test_warning("Region A", "Sturm", 0, None)]);
+        let current = test_warning_list(vec![test_warning("Region B", "Sturm", 0, None)]);
+
+        let changes = diff_warning_lists(&previous, &current);
+
+        assert!(matches!(&changes[0], WarningChange::Added(w) if w.region_name == "Region B"));
+        assert!(matches!(&changes[1], WarningChange::Removed(w) if w.region_name == "Region A"));
+    }
+
+    #[test]
+    fn diff_reports_expired() {
+        let now = Utc::now().timestamp_millis();
+        let previous = test_warning_list(vec![test_warning(
+            "Region A",
+            "Sturm",
+            0,
+            Some(now + 1_000_000),
+        )]);
+        let current = test_warning_list(vec![test_warning(
+            "Region A",
+            "Sturm",
+            0,
+            Some(now - 1_000_000),
+        )]);
+
+        let changes = diff_warning_lists(&previous, &current);
+
+        assert_eq!(changes.len(), 1);
+        assert!(matches!(&changes[0], WarningChange::Expired(w) if w.region_name == "Region A"));
+    }
+
+    #[test]
+    fn lossy_string_replaces_lone_surrogate() {
+        // A lone high surrogate with no matching low surrogate.
+        assert_eq!(unescape_json_string_lossy("a\\ud83db"), "a\u{FFFD}b");
+    }
+
+    #[test]
+    fn lossy_string_decodes_surrogate_pair() {
+        // A valid surrogate pair for U+1F600 (grinning face emoji).
+        assert_eq!(unescape_json_string_lossy("\\ud83d\\ude00"), "\u{1F600}");
+    }
+
+    #[test]
+    fn lossy_string_decodes_backspace_and_form_feed() {
+        assert_eq!(unescape_json_string_lossy("\\b\\f"), "\u{08}\u{0C}");
+    }
+
+    #[test]
+    fn sanitize_replaces_invalid_escape() {
+        assert_eq!(
+            sanitize_invalid_json_escapes(r#""a\zb""#),
+            "\"a\\ufffdb\""
+        );
+    }
+
+    #[test]
+    fn sanitize_leaves_valid_escapes_untouched() {
+        let text = r#""a\n\t\"\\b""#;
+        assert_eq!(sanitize_invalid_json_escapes(text), text);
+    }
+
+    #[test]
+    fn invalid_escape_does_not_discard_the_whole_payload() {
+        // `\z` is not a valid JSON escape and would otherwise make `serde_json` reject the
+        // entire document before any per-field deserializer gets a chance to run.
+        let raw = r#"warnWetter.loadWarnings({"time":0,"warnings":{"1":[{"state":"","type":0,"level":0,"start":0,"regionName":"Region A","event":"Sturm","headline":"a\zb","instruction":"","description":"","stateShort":""}]},"vorabInformation":{},"copyright":""});"#;
+
+        let warning_list = parse_response(raw).unwrap();
+
+        assert_eq!(warning_list.warnings.len(), 1);
+        assert_eq!(warning_list.warnings[0].headline, "a\u{FFFD}b");
+    }
+
+    #[test]
+    fn filters_compose() {
+        let mut low_level = test_warning("Region A", "Sturm", 0, None);
+        low_level.state = "Bayern".to_owned();
+        low_level.level = 1;
+        low_level.warn_cell_id = "1".to_owned();
+
+        let mut high_level = test_warning("Region B", "Gewitter", 0, None);
+        high_level.state = "Bayern".to_owned();
+        high_level.level = 3;
+        high_level.warn_cell_id = "2".to_owned();
+
+        let list = test_warning_list(vec![low_level, high_level]);
+
+        let filtered = list.filter_by_state("Bayern").with_min_level(2);
+        assert_eq!(filtered.warnings.len(), 1);
+        assert_eq!(filtered.warnings[0].warn_cell_id, "2");
+    }
+
     #[test]
-    #[should_panic]
     fn oob_date_fails() {
         let w_dw = WarningRaw {
             state: String::new(),
@@ -245,6 +731,12 @@ mod tests {
             altitude_end: None,
         };
 
-        let _ = Warning::from(w_dw);
+        let result = Warning::try_from((String::new(), w_dw));
+        assert!(matches!(
+            result,
+            Err(Error::DateParsingError {
+                timestamp: 7346982752374653336
+            })
+        ));
     }
 }